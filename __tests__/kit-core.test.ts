@@ -205,6 +205,37 @@ describe('kit core', () => {
     ).toThrow(/at least AGENTS\.md or Skills package/)
   })
 
+  it('keeps file-level safety findings without a line number as null', async () => {
+    const core = await importCore()
+
+    const loadout = core.addKitLoadout({
+      name: 'imported-loadout',
+      items: [{ skillPath: '/tmp/skills/risky', mode: 'copy', sortOrder: 0 }],
+      importSource: {
+        repoWebUrl: 'https://github.com/acme/skills',
+        repoUrl: 'https://github.com/acme/skills.git',
+        originalUrl: 'https://github.com/acme/skills',
+        rootSubdir: '/',
+        importedAt: '2024-01-01T00:00:00.000Z',
+        lastSourceUpdatedAt: '2024-01-01T00:00:00.000Z',
+        lastSafetyCheck: {
+          checkedAt: now,
+          status: 'warn',
+          scannedFiles: 2,
+          warnings: [],
+          flaggedFiles: ['risky/tool.py', 'risky/SKILL.md'],
+          findings: [
+            { rule: 'executable-bit', severity: 'warn', file: 'risky/tool.py', line: null, message: '' },
+            { rule: 'pipe-to-shell', severity: 'warn', file: 'risky/SKILL.md', line: 5, message: '' },
+          ],
+        },
+      },
+    })
+
+    const findings = loadout!.importSource?.lastSafetyCheck?.findings ?? []
+    expect(findings.map((finding) => finding.line)).toEqual([null, 5])
+  })
+
   it('restores a managed official kit baseline', async () => {
     const core = await importCore()
 
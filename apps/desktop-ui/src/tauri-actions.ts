@@ -1,11 +1,14 @@
 import { invoke } from '@tauri-apps/api/core'
 import { open as openDirectoryDialog } from '@tauri-apps/plugin-dialog'
 import type {
+  ImportSafetyPolicy,
   KitApplyResult,
+  KitLoadoutImportPlan,
   KitLoadoutImportResult,
   KitLoadoutRecord,
   KitPolicyRecord,
   KitRecord,
+  KitSafetyCheck,
   KitSyncMode,
   OfficialPresetBatchInstallResult,
   OfficialPresetDetail,
@@ -21,7 +24,35 @@ import type {
   UniversalProviderRecord,
 } from '@/lib/core/provider-types'
 import type { AgentConfig, AppConfig } from '@/lib/config'
-import type { Skill } from '@/lib/skills-types'
+import type {
+  GitCacheEntry,
+  GitCacheGcResult,
+  GitCredentialKind,
+  GitErrorInfo,
+  GitErrorKind,
+  GitHostCredential,
+  HubRelocationResult,
+  Skill,
+  SkillAdoptionCandidate,
+  SkillAdoptionInput,
+  SkillAdoptionItemResult,
+  SkillConsolidationResult,
+  SkillCopyStatus,
+  SkillDiagnostic,
+  SkillDocument,
+  SkillDuplicateGroup,
+  SkillFileUpdateInput,
+  SkillLinkHealth,
+  SkillLinkRepairAction,
+  SkillProvenanceMigrationResult,
+  SkillResyncResult,
+  SkillSearchQuery,
+  SkillSearchResult,
+  SkillUpdateCheck,
+  SkillUpdateResult,
+  SkillVersion,
+  SkillVersionDiff,
+} from '@/lib/skills-types'
 import { resolveAgentsFromGithub } from '@/lib/github-agents'
 import { getMutableState, updateState } from './desktop-state'
 
@@ -46,6 +77,10 @@ type SkillOperationResult = {
   message: string
 }
 
+export type SkillImportResult = SkillOperationResult & {
+  safetyCheck: KitSafetyCheck
+}
+
 function isTauriRuntime(): boolean {
   if (typeof window === 'undefined') {
     return false
@@ -67,12 +102,41 @@ function stringifyError(error: unknown): string {
   }
 }
 
+const GIT_ERROR_CODES: Record<string, GitErrorKind> = {
+  GIT_AUTH_REQUIRED: 'authRequired',
+  GIT_REF_NOT_FOUND: 'refNotFound',
+  GIT_REPOSITORY_NOT_FOUND: 'repositoryNotFound',
+  GIT_NETWORK_UNREACHABLE: 'networkUnreachable',
+  GIT_ERROR: 'other',
+}
+
+// Git failures arrive as `<CODE>::<host>::<message>`; the host is empty when unknown.
+export function parseGitError(error: unknown): GitErrorInfo | null {
+  const cause = stringifyError(error)
+  const [code, host, ...message] = cause.split('::')
+  const kind = GIT_ERROR_CODES[code]
+  if (!kind || host === undefined || message.length === 0) {
+    return null
+  }
+  return { kind, host: host || null, message: message.join('::') }
+}
+
+// Returns the host to prompt credentials for when an import or update check was rejected.
+export function parseGitAuthRequiredHost(error: unknown): string | null {
+  const gitError = parseGitError(error)
+  return gitError?.kind === 'authRequired' ? gitError.host : null
+}
+
 async function invokeCommand<T>(command: string, args?: Record<string, unknown>): Promise<T> {
   try {
     return await invoke<T>(command, args)
   } catch (error) {
     const cause = stringifyError(error)
-    if (cause.startsWith('AGENTS_MD_EXISTS::') || cause.startsWith('POLICY_FILE_EXISTS::')) {
+    if (
+      cause.startsWith('AGENTS_MD_EXISTS::') ||
+      cause.startsWith('POLICY_FILE_EXISTS::') ||
+      parseGitError(cause)
+    ) {
       throw new Error(cause)
     }
     throw new Error(`${command}: ${cause}`)
@@ -532,6 +596,8 @@ export async function actionKitLoadoutImportFromRepo(values: {
   name?: string
   description?: string
   overwrite?: boolean
+  reference?: string
+  upgrade?: boolean
 }) {
   if (!isTauriRuntime()) {
     throw new Error('Tauri runtime is required.')
@@ -542,11 +608,67 @@ export async function actionKitLoadoutImportFromRepo(values: {
     name: values.name,
     description: values.description,
     overwrite: values.overwrite,
+    reference: values.reference,
+    upgrade: values.upgrade,
+  })
+  await Promise.all([refreshKitState(), refreshSkillState()])
+  return result
+}
+
+export async function actionKitLoadoutImportPreview(values: {
+  url: string
+  name?: string
+  description?: string
+  reference?: string
+  upgrade?: boolean
+  skillNames?: string[]
+}) {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<KitLoadoutImportPlan>('kit_loadout_import_preview', values)
+}
+
+export async function actionKitLoadoutImportCommit(planId: string, overwrite?: boolean) {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  const result = await invokeCommand<KitLoadoutImportResult>('kit_loadout_import_commit', {
+    planId,
+    overwrite,
   })
   await Promise.all([refreshKitState(), refreshSkillState()])
   return result
 }
 
+export async function actionKitLoadoutImportDiscard(planId: string) {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<boolean>('kit_loadout_import_discard', { planId })
+}
+
+export async function actionImportSafetyPolicyGet(): Promise<ImportSafetyPolicy> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<ImportSafetyPolicy>('import_safety_policy_get')
+}
+
+export async function actionImportSafetyPolicySet(
+  policy: ImportSafetyPolicy
+): Promise<ImportSafetyPolicy> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<ImportSafetyPolicy>('import_safety_policy_set', { policy })
+}
+
 export async function actionKitList() {
   if (!isTauriRuntime()) {
     throw new Error('Tauri runtime is required.')
@@ -692,7 +814,7 @@ export async function actionOfficialPresetInstallAll(values?: { overwrite?: bool
 export async function actionSyncSkill(
   source: string,
   destParent: string,
-  syncMode?: 'copy' | 'link'
+  syncMode?: KitSyncMode
 ) {
   if (!isTauriRuntime()) {
     throw new Error('Tauri runtime is required.')
@@ -914,16 +1036,105 @@ export async function actionOpenExternal(url: string) {
   return invokeCommand<boolean>('open_external_url', { url })
 }
 
-export async function actionImportSkill(url: string): Promise<SkillOperationResult> {
+export async function actionImportSkill(
+  url: string,
+  reference?: string
+): Promise<SkillImportResult> {
   if (!isTauriRuntime()) {
     throw new Error('Tauri runtime is required.')
   }
 
-  const result = await invokeCommand<SkillOperationResult>('skill_import', { url })
+  const result = await invokeCommand<SkillImportResult>('skill_import', { url, reference })
   await refreshSkillState()
   return result
 }
 
+export async function actionCheckSkillUpdates(): Promise<SkillUpdateCheck[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<SkillUpdateCheck[]>('skill_update_check')
+}
+
+export async function actionSkillCopiesList(skillPath: string): Promise<SkillCopyStatus[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<SkillCopyStatus[]>('skill_copies_list', { skillPath })
+}
+
+export async function actionSkillResyncCopies(values: {
+  skillPath: string
+  destinations?: string[]
+  force?: boolean
+}): Promise<SkillResyncResult> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  const result = await invokeCommand<SkillResyncResult>('skill_resync_copies', {
+    skillPath: values.skillPath,
+    destinations: values.destinations,
+    force: values.force,
+  })
+  await refreshSkillState()
+  return result
+}
+
+export async function actionGitCacheList(): Promise<GitCacheEntry[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<GitCacheEntry[]>('git_cache_list')
+}
+
+export async function actionGitCacheGc(maxBytes?: number): Promise<GitCacheGcResult> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<GitCacheGcResult>('git_cache_gc', { maxBytes })
+}
+
+export async function actionGitCredentialList(): Promise<GitHostCredential[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<GitHostCredential[]>('git_credential_list')
+}
+
+export async function actionGitCredentialSave(values: {
+  host: string
+  kind: GitCredentialKind
+  username?: string
+  sshKeyPath?: string
+  secret?: string
+}): Promise<GitHostCredential> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<GitHostCredential>('git_credential_save', {
+    host: values.host,
+    kind: values.kind,
+    username: values.username,
+    sshKeyPath: values.sshKeyPath,
+    secret: values.secret,
+  })
+}
+
+export async function actionGitCredentialDelete(host: string): Promise<boolean> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<boolean>('git_credential_delete', { host })
+}
+
 export async function actionCreateSkill(values: {
   name: string
   description: string
@@ -941,3 +1152,175 @@ export async function actionCreateSkill(values: {
   await refreshSkillState()
   return result
 }
+
+export async function actionSkillLinksScan(): Promise<SkillLinkHealth[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<SkillLinkHealth[]>('skill_links_scan')
+}
+
+export async function actionSkillLinkRepair(values: {
+  linkPath: string
+  action: SkillLinkRepairAction
+  targetSkillPath?: string
+}): Promise<SkillOperationResult> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  const result = await invokeCommand<SkillOperationResult>('skill_link_repair', {
+    linkPath: values.linkPath,
+    action: values.action,
+    targetSkillPath: values.targetSkillPath,
+  })
+  await refreshSkillState()
+  return result
+}
+
+export async function actionHubRelocate(newHubPath: string): Promise<HubRelocationResult> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  const result = await invokeCommand<HubRelocationResult>('hub_relocate', { newHubPath })
+  await Promise.all([refreshConfigState(), refreshSkillState()])
+  return result
+}
+
+export async function actionSkillVersionsList(skillPath: string): Promise<SkillVersion[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<SkillVersion[]>('skill_versions_list', { skillPath })
+}
+
+// Without `toVersion` the diff runs against the current working copy.
+export async function actionSkillVersionDiff(values: {
+  skillPath: string
+  fromVersion: string
+  toVersion?: string
+}): Promise<SkillVersionDiff> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<SkillVersionDiff>('skill_version_diff', {
+    skillPath: values.skillPath,
+    fromVersion: values.fromVersion,
+    toVersion: values.toVersion,
+  })
+}
+
+export async function actionSkillVersionRestore(values: {
+  skillPath: string
+  versionId: string
+}): Promise<SkillOperationResult> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  const result = await invokeCommand<SkillOperationResult>('skill_version_restore', {
+    skillPath: values.skillPath,
+    versionId: values.versionId,
+  })
+  await refreshSkillState()
+  return result
+}
+
+export async function actionSkillUpdate(values: {
+  path: string
+  document: SkillDocument
+  newName?: string
+  files?: SkillFileUpdateInput[]
+}): Promise<SkillUpdateResult> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  const result = await invokeCommand<SkillUpdateResult>('skill_update', {
+    path: values.path,
+    document: values.document,
+    newName: values.newName,
+    files: values.files,
+  })
+  await refreshSkillState()
+  return result
+}
+
+export async function actionSkillLint(path: string): Promise<SkillDiagnostic[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<SkillDiagnostic[]>('skill_lint', { path })
+}
+
+export async function actionSkillProvenanceMigrate(): Promise<SkillProvenanceMigrationResult> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  const result = await invokeCommand<SkillProvenanceMigrationResult>('skill_provenance_migrate')
+  await refreshSkillState()
+  return result
+}
+
+export async function actionSkillSearch(query: SkillSearchQuery): Promise<SkillSearchResult[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<SkillSearchResult[]>('skill_search', { query })
+}
+
+export async function actionSkillDuplicatesScan(): Promise<SkillDuplicateGroup[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<SkillDuplicateGroup[]>('skill_duplicates_scan')
+}
+
+// `duplicatePaths` must come from the same group as `keepPath`.
+export async function actionSkillDuplicatesConsolidate(values: {
+  keepPath: string
+  duplicatePaths: string[]
+}): Promise<SkillConsolidationResult> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  const result = await invokeCommand<SkillConsolidationResult>('skill_duplicates_consolidate', {
+    keepPath: values.keepPath,
+    duplicatePaths: values.duplicatePaths,
+  })
+  await refreshSkillState()
+  return result
+}
+
+export async function actionSkillAdoptionScan(): Promise<SkillAdoptionCandidate[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  return invokeCommand<SkillAdoptionCandidate[]>('skill_adoption_scan')
+}
+
+export async function actionSkillAdoptionApply(values: {
+  items: SkillAdoptionInput[]
+  replaceWithLinks?: boolean
+}): Promise<SkillAdoptionItemResult[]> {
+  if (!isTauriRuntime()) {
+    throw new Error('Tauri runtime is required.')
+  }
+
+  const result = await invokeCommand<SkillAdoptionItemResult[]>('skill_adoption_apply', {
+    items: values.items,
+    replaceWithLinks: values.replaceWithLinks,
+  })
+  await refreshSkillState()
+  return result
+}
@@ -4,6 +4,7 @@ import { useState, useEffect } from 'react'
 import styles from './SyncModal.module.css' // Reusing modal styles
 import { X, Download, GitBranch } from 'lucide-react'
 import { actionImportSkill } from '@/apps/desktop-ui/src/tauri-actions'
+import type { KitSafetyCheck } from '@/lib/core/kit-types'
 
 interface ImportSkillModalProps {
   isOpen: boolean
@@ -13,7 +14,11 @@ interface ImportSkillModalProps {
 export function ImportSkillModal({ isOpen, onClose }: ImportSkillModalProps) {
   const [url, setUrl] = useState('')
   const [isLoading, setIsLoading] = useState(false)
-  const [result, setResult] = useState<{ success: boolean; message: string } | null>(null)
+  const [result, setResult] = useState<{
+    success: boolean
+    message: string
+    safetyCheck?: KitSafetyCheck
+  } | null>(null)
 
   // Reset state when modal opens
   useEffect(() => {
@@ -35,7 +40,7 @@ export function ImportSkillModal({ isOpen, onClose }: ImportSkillModalProps) {
       const res = await actionImportSkill(url)
 
       if (res?.success) {
-        setResult({ success: true, message: res.message })
+        setResult({ success: true, message: res.message, safetyCheck: res.safetyCheck })
         setUrl('')
         // Close after a short delay on success or let user close?
         // Let's keep it open to show success message, or close immediately.
@@ -84,6 +89,21 @@ export function ImportSkillModal({ isOpen, onClose }: ImportSkillModalProps) {
                 className={`mt-4 p-3 rounded text-sm ${result.success ? 'bg-green-50 text-green-700 border border-green-200' : 'bg-red-50 text-red-700 border border-red-200'}`}
               >
                 {result.message}
+                {result.safetyCheck && result.safetyCheck.status !== 'pass' && (
+                  <ul className="mt-2 text-xs text-amber-700 list-disc pl-4">
+                    {(result.safetyCheck.findings ?? []).length > 0
+                      ? result.safetyCheck.findings?.map((finding, index) => (
+                          <li key={`${finding.file}:${finding.line ?? ''}:${index}`}>
+                            <span className="font-mono">
+                              {finding.file}
+                              {finding.line != null ? `:${finding.line}` : ''}
+                            </span>{' '}
+                            {finding.message}
+                          </li>
+                        ))
+                      : result.safetyCheck.warnings.map((warning) => <li key={warning}>{warning}</li>)}
+                  </ul>
+                )}
               </div>
             )}
           </div>
@@ -1142,6 +1142,7 @@ export function KitPanel({
 
       const showSuccess = (params: {
         successCount: number
+        fallbackCount: number
         policyPath?: string
         policyFileName?: string
         overwrote: boolean
@@ -1151,17 +1152,24 @@ export function KitPanel({
         const policySummary = params.policyPath
           ? `，${policyFileName} 已写入 ${params.policyPath}`
           : ''
+        const fallbackSuffix =
+          params.fallbackCount > 0 ? `（${params.fallbackCount} 个文件无法链接，已改为复制）` : ''
         setMessage({
           type: 'success',
-          text: `Kit 应用完成：${params.successCount} 个 Skills 已同步${policySummary}${overwriteSuffix}`,
+          text: `Kit 应用完成：${params.successCount} 个 Skills 已同步${fallbackSuffix}${policySummary}${overwriteSuffix}`,
         })
       }
 
       try {
         const result = await applyOnce(false)
         const successCount = result.loadoutResults.filter((row) => row.status === 'success').length
+        const fallbackCount = result.loadoutResults.reduce(
+          (count, row) => count + (row.fallbackFiles?.length ?? 0),
+          0
+        )
         showSuccess({
           successCount,
+          fallbackCount,
           policyPath: result.policyPath,
           policyFileName: result.policyFileName,
           overwrote: Boolean(result.overwroteAgentsMd),
@@ -1740,13 +1748,13 @@ export function KitPanel({
               <div className="text-xs text-gray-500">Sync 方式</div>
               <select
                 value={applySyncMode}
-                onChange={(event) =>
-                  setApplySyncMode(event.target.value === 'link' ? 'link' : 'copy')
-                }
+                onChange={(event) => setApplySyncMode(event.target.value as KitSyncMode)}
                 className="w-full rounded-md border border-gray-200 px-3 py-2 text-sm"
               >
                 <option value="copy">copy</option>
                 <option value="link">link</option>
+                <option value="hardlink">hardlink</option>
+                <option value="reflink">reflink</option>
               </select>
             </div>
 
@@ -2,10 +2,21 @@
 
 import { Skill } from '@/lib/skills-types'
 import { AppConfig } from '@/lib/config'
+import type { KitSyncMode } from '@/lib/core/kit-types'
 import styles from './SyncModal.module.css'
 import { useEffect, useState } from 'react'
 import { actionSyncSkill } from '@/apps/desktop-ui/src/tauri-actions'
-import { X, Share2, Folder, Monitor, Copy, Link as LinkIcon } from 'lucide-react'
+import {
+  X,
+  Share2,
+  Folder,
+  Monitor,
+  Copy,
+  Layers,
+  Link as LinkIcon,
+  Link2,
+  type LucideIcon,
+} from 'lucide-react'
 
 interface SyncModalProps {
   skill: Skill
@@ -14,6 +25,40 @@ interface SyncModalProps {
   onClose: () => void
 }
 
+const SYNC_MODE_OPTIONS: Array<{
+  mode: KitSyncMode
+  label: string
+  icon: LucideIcon
+  description: string
+}> = [
+  {
+    mode: 'copy',
+    label: 'Copy (Clone)',
+    icon: Copy,
+    description: 'Creates an independent copy. Changes to source are not reflected until re-sync.',
+  },
+  {
+    mode: 'link',
+    label: 'Link (Symlink)',
+    icon: LinkIcon,
+    description: 'Creates a symbolic link. Changes to source are immediately reflected.',
+  },
+  {
+    mode: 'hardlink',
+    label: 'Hardlink',
+    icon: Link2,
+    description:
+      'Hardlinks every file except SKILL.md, so edits to source files are shared. Falls back to copying files that cannot be linked.',
+  },
+  {
+    mode: 'reflink',
+    label: 'Reflink (CoW)',
+    icon: Layers,
+    description:
+      'Creates a copy-on-write clone that shares disk space until edited. Falls back to copying on filesystems without support.',
+  },
+]
+
 interface Target {
   id: string
   type: 'agent' | 'project'
@@ -23,7 +68,7 @@ interface Target {
 
 export function SyncModal({ skill, config, isOpen, onClose }: SyncModalProps) {
   const [selectedTargets, setSelectedTargets] = useState<string[]>([])
-  const [syncMode, setSyncMode] = useState<'copy' | 'link'>('copy')
+  const [syncMode, setSyncMode] = useState<KitSyncMode>('copy')
   const [isSyncing, setIsSyncing] = useState(false)
 
   useEffect(() => {
@@ -132,34 +177,23 @@ export function SyncModal({ skill, config, isOpen, onClose }: SyncModalProps) {
           {/* Sync Mode Selection */}
           <div className={styles.section} style={{ marginTop: '16px' }}>
             <div className={styles.label}>Sync Mode</div>
-            <div className="flex gap-4 mt-2">
-              <label className="flex items-center gap-2 cursor-pointer">
-                <input
-                  type="radio"
-                  name="syncMode"
-                  value="copy"
-                  checked={syncMode === 'copy'}
-                  onChange={() => setSyncMode('copy')}
-                />
-                <Copy size={14} className="text-gray-500" />
-                <span className="text-sm">Copy (Clone)</span>
-              </label>
-              <label className="flex items-center gap-2 cursor-pointer">
-                <input
-                  type="radio"
-                  name="syncMode"
-                  value="link"
-                  checked={syncMode === 'link'}
-                  onChange={() => setSyncMode('link')}
-                />
-                <LinkIcon size={14} className="text-gray-500" />
-                <span className="text-sm">Link (Symlink)</span>
-              </label>
+            <div className="flex flex-wrap gap-4 mt-2">
+              {SYNC_MODE_OPTIONS.map(({ mode, label, icon: Icon }) => (
+                <label key={mode} className="flex items-center gap-2 cursor-pointer">
+                  <input
+                    type="radio"
+                    name="syncMode"
+                    value={mode}
+                    checked={syncMode === mode}
+                    onChange={() => setSyncMode(mode)}
+                  />
+                  <Icon size={14} className="text-gray-500" />
+                  <span className="text-sm">{label}</span>
+                </label>
+              ))}
             </div>
             <div className="text-xs text-gray-400 mt-1 ml-1">
-              {syncMode === 'copy'
-                ? 'Creates an independent copy. Changes to source are not reflected until re-sync.'
-                : 'Creates a symbolic link. Changes to source are immediately reflected.'}
+              {SYNC_MODE_OPTIONS.find((option) => option.mode === syncMode)?.description}
             </div>
           </div>
         </div>
@@ -4,6 +4,7 @@ import type {
   KitLoadoutRecord,
   KitPolicyRecord,
   KitRecord,
+  KitSyncMode,
 } from './kit-types'
 
 export function ensureDb(): unknown
@@ -29,14 +30,14 @@ export function getKitLoadoutById(id: string): KitLoadoutRecord | null
 export function addKitLoadout(input: {
   name: string
   description?: string
-  items: Array<{ skillPath: string; mode?: 'copy' | 'link'; sortOrder?: number }>
+  items: Array<{ skillPath: string; mode?: KitSyncMode; sortOrder?: number }>
   importSource?: KitLoadoutImportSource
 }): KitLoadoutRecord
 export function updateKitLoadout(input: {
   id: string
   name?: string
   description?: string
-  items?: Array<{ skillPath: string; mode?: 'copy' | 'link'; sortOrder?: number }>
+  items?: Array<{ skillPath: string; mode?: KitSyncMode; sortOrder?: number }>
   importSource?: KitLoadoutImportSource
 }): KitLoadoutRecord
 export function deleteKitLoadout(id: string): boolean
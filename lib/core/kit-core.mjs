@@ -128,6 +128,8 @@ function normalizeLoadoutImportSource(value) {
   const importedAt = String(value.importedAt || '').trim()
   const lastSourceUpdatedAt = String(value.lastSourceUpdatedAt || '').trim()
   const lastSafetyCheck = normalizeKitSafetyCheck(value.lastSafetyCheck)
+  const pinnedRef = toOptionalText(value.pinnedRef)
+  const resolvedCommit = toOptionalText(value.resolvedCommit)
 
   if (!repoWebUrl || !repoUrl || !originalUrl || !importedAt || !lastSourceUpdatedAt) {
     return undefined
@@ -142,6 +144,8 @@ function normalizeLoadoutImportSource(value) {
     importedAt,
     lastSourceUpdatedAt,
     lastSafetyCheck,
+    pinnedRef,
+    resolvedCommit,
   }
 }
 
@@ -151,7 +155,7 @@ function normalizeKitSafetyCheck(value) {
   }
 
   const checkedAt = Number(value.checkedAt)
-  const status = value.status === 'warn' ? 'warn' : 'pass'
+  const status = value.status === 'warn' || value.status === 'fail' ? value.status : 'pass'
   const scannedFiles = Number(value.scannedFiles)
   const warnings = Array.isArray(value.warnings)
     ? value.warnings.map((entry) => String(entry || '').trim()).filter(Boolean)
@@ -159,6 +163,18 @@ function normalizeKitSafetyCheck(value) {
   const flaggedFiles = Array.isArray(value.flaggedFiles)
     ? value.flaggedFiles.map((entry) => String(entry || '').trim()).filter(Boolean)
     : []
+  const findings = Array.isArray(value.findings)
+    ? value.findings
+        .filter((entry) => entry && typeof entry === 'object' && entry.rule)
+        .map((entry) => ({
+          rule: String(entry.rule),
+          severity: ['off', 'info', 'warn', 'fail'].includes(entry.severity) ? entry.severity : 'warn',
+          file: String(entry.file || ''),
+          line:
+            entry.line != null && Number.isFinite(Number(entry.line)) ? Number(entry.line) : null,
+          message: String(entry.message || ''),
+        }))
+    : []
 
   if (!Number.isFinite(checkedAt) || !Number.isFinite(scannedFiles)) {
     return undefined
@@ -170,6 +186,7 @@ function normalizeKitSafetyCheck(value) {
     scannedFiles,
     warnings,
     flaggedFiles,
+    findings,
   }
 }
 
@@ -302,6 +319,12 @@ function normalizeManagedSource(value) {
   }
 }
 
+const KIT_SYNC_MODES = new Set(['copy', 'link', 'hardlink', 'reflink'])
+
+function normalizeKitSyncMode(value) {
+  return KIT_SYNC_MODES.has(value) ? value : 'copy'
+}
+
 function normalizeLoadoutItems(items) {
   if (!Array.isArray(items)) {
     throw new Error('Skills package items must be an array')
@@ -320,7 +343,7 @@ function normalizeLoadoutItems(items) {
       continue
     }
 
-    const mode = item.mode === 'link' ? 'link' : 'copy'
+    const mode = normalizeKitSyncMode(item.mode)
     const sortOrder = Number.isInteger(item.sortOrder) ? item.sortOrder : index
 
     normalized.push({ skillPath, mode, sortOrder })
@@ -345,7 +368,7 @@ function parsePolicyRow(row) {
 function parseLoadoutItems(rows) {
   return rows.map((row) => ({
     skillPath: row.skill_path,
-    mode: row.mode === 'link' ? 'link' : 'copy',
+    mode: normalizeKitSyncMode(row.mode),
     sortOrder: row.sort_order,
   }))
 }
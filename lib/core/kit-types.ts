@@ -1,4 +1,4 @@
-export type KitSyncMode = 'copy' | 'link'
+export type KitSyncMode = 'copy' | 'link' | 'hardlink' | 'reflink'
 
 export interface KitLoadoutItem {
   skillPath: string
@@ -15,6 +15,8 @@ export interface KitLoadoutImportSource {
   importedAt: string
   lastSourceUpdatedAt: string
   lastSafetyCheck?: KitSafetyCheck
+  pinnedRef?: string
+  resolvedCommit?: string
 }
 
 export interface KitLoadoutRecord {
@@ -36,12 +38,29 @@ export interface KitPolicyRecord {
   updatedAt: number
 }
 
+export type ImportSafetySeverity = 'off' | 'info' | 'warn' | 'fail'
+
+export interface KitSafetyFinding {
+  rule: string
+  severity: ImportSafetySeverity
+  file: string
+  line?: number | null
+  message: string
+}
+
 export interface KitSafetyCheck {
   checkedAt: number
-  status: 'pass' | 'warn'
+  status: 'pass' | 'warn' | 'fail'
   scannedFiles: number
   warnings: string[]
   flaggedFiles: string[]
+  findings?: KitSafetyFinding[]
+}
+
+export interface ImportSafetyPolicy {
+  blockOnFail: boolean
+  maxFileBytes: number
+  severities: Record<string, ImportSafetySeverity>
 }
 
 export interface ManagedKitPolicyBaseline {
@@ -103,6 +122,7 @@ export interface KitApplySkillResult {
   destination: string
   status: 'success' | 'failed'
   error?: string
+  fallbackFiles?: string[]
 }
 
 export interface KitApplyResult {
@@ -127,6 +147,28 @@ export interface KitLoadoutImportResult {
   source: KitLoadoutImportSource
 }
 
+export interface KitLoadoutImportPlanSkill {
+  name: string
+  skillPath: string
+  relativePath: string
+  files: { path: string; change: 'added' | 'modified' | 'removed'; patch?: string | null }[]
+}
+
+export interface KitLoadoutImportPlan {
+  planId: string
+  loadoutName: string
+  loadoutStatus: 'created' | 'updated'
+  source: KitLoadoutImportSource
+  newSkills: KitLoadoutImportPlanSkill[]
+  changedSkills: KitLoadoutImportPlanSkill[]
+  conflictingSkills: KitLoadoutImportPlanSkill[]
+  unchangedSkills: string[]
+  removedSkills: string[]
+  safetyCheck?: KitSafetyCheck
+  blocked: boolean
+  expiresAt: number
+}
+
 export interface OfficialPresetSummary {
   id: string
   name: string
@@ -45,8 +45,10 @@ function resolveInstructionFileName(agent) {
     : DEFAULT_POLICY_FILE_NAME
 }
 
+const KIT_SYNC_MODES = new Set(['copy', 'link', 'hardlink', 'reflink'])
+
 function normalizeKitMode(value) {
-  return value === 'link' ? 'link' : 'copy'
+  return KIT_SYNC_MODES.has(value) ? value : 'copy'
 }
 
 function normalizeLoadoutItems(items) {
@@ -439,12 +441,92 @@ async function atomicWriteText(filePath, content) {
   await fs.move(tempPath, filePath, { overwrite: true })
 }
 
+// Hardlink and reflink fall back to a plain copy per file; SKILL.md is always copied
+// because it receives per-project metadata stamps.
+async function copySkillFileWithMode(sourcePath, destPath, syncMode) {
+  if (syncMode === 'hardlink' && path.basename(sourcePath) !== 'SKILL.md') {
+    try {
+      await fs.link(sourcePath, destPath)
+      return false
+    } catch {
+      await fs.copyFile(sourcePath, destPath)
+      return true
+    }
+  }
+
+  if (syncMode === 'reflink') {
+    try {
+      await fs.copyFile(sourcePath, destPath, fs.constants.COPYFILE_FICLONE_FORCE)
+      return false
+    } catch {
+      await fs.copyFile(sourcePath, destPath)
+      return true
+    }
+  }
+
+  await fs.copyFile(sourcePath, destPath)
+  return false
+}
+
+function isPathWithin(targetPath, rootPath) {
+  const relative = path.relative(rootPath, targetPath)
+  return relative === '' || (!relative.startsWith('..') && !path.isAbsolute(relative))
+}
+
+// Mirrors the desktop app: a link is kept only when it resolves inside the source root,
+// both lexically and through any further links; absolute targets become relative.
+async function containedSymlinkTarget(linkPath, rootPath) {
+  const target = await fs.readlink(linkPath).catch(() => null)
+  if (target === null) {
+    return null
+  }
+
+  const resolved = path.resolve(path.dirname(linkPath), target)
+  if (!isPathWithin(resolved, path.resolve(rootPath))) {
+    return null
+  }
+  const [realTarget, realRoot] = await Promise.all([
+    fs.realpath(linkPath).catch(() => null),
+    fs.realpath(rootPath).catch(() => null),
+  ])
+  if (realTarget && realRoot && !isPathWithin(realTarget, realRoot)) {
+    return null
+  }
+
+  return path.isAbsolute(target) ? path.relative(path.dirname(linkPath), resolved) || '.' : target
+}
+
+async function copySkillTreeWithMode(sourceRoot, sourceDir, destDir, syncMode, fallbackFiles) {
+  await fs.ensureDir(destDir)
+  for (const entry of await fs.readdir(sourceDir, { withFileTypes: true })) {
+    if (entry.name === '.git') continue
+    const sourcePath = path.join(sourceDir, entry.name)
+    const destPath = path.join(destDir, entry.name)
+    if (entry.isDirectory()) {
+      await copySkillTreeWithMode(sourceRoot, sourcePath, destPath, syncMode, fallbackFiles)
+    } else if (entry.isSymbolicLink()) {
+      // Links that resolve outside the source (e.g. to ~/.ssh) are dropped, not followed.
+      const target = await containedSymlinkTarget(sourcePath, sourceRoot)
+      if (target !== null) {
+        await fs.remove(destPath)
+        await fs.symlink(target, destPath)
+      }
+    } else if (entry.isFile()) {
+      if (await copySkillFileWithMode(sourcePath, destPath, syncMode)) {
+        fallbackFiles.push(path.relative(sourceRoot, sourcePath).split(path.sep).join('/'))
+      }
+    } else {
+      throw new Error(`Refusing to copy special file ${sourcePath}`)
+    }
+  }
+}
+
 async function syncSkill(sourcePath, destParentPath, syncMode = 'copy') {
   const skillDirName = path.basename(sourcePath)
   const destPath = path.join(destParentPath, skillDirName)
 
   if (sourcePath === destPath) {
-    return destPath
+    return { destination: destPath, fallbackFiles: [] }
   }
 
   await fs.ensureDir(destParentPath)
@@ -452,7 +534,7 @@ async function syncSkill(sourcePath, destParentPath, syncMode = 'copy') {
   if (syncMode === 'link') {
     await fs.remove(destPath)
     await fs.ensureSymlink(sourcePath, destPath)
-    return destPath
+    return { destination: destPath, fallbackFiles: [] }
   }
 
   const isSymlink = await fs
@@ -464,8 +546,16 @@ async function syncSkill(sourcePath, destParentPath, syncMode = 'copy') {
     await fs.remove(destPath)
   }
 
-  await fs.copy(sourcePath, destPath, { overwrite: true, errorOnExist: false })
-  return destPath
+  if (syncMode === 'hardlink' || syncMode === 'reflink') {
+    // Linked files must not keep stale content from a previous copy.
+    await fs.remove(destPath)
+    const fallbackFiles = []
+    await copySkillTreeWithMode(sourcePath, sourcePath, destPath, syncMode, fallbackFiles)
+    return { destination: destPath, fallbackFiles }
+  }
+
+  await copySkillTreeWithMode(sourcePath, sourcePath, destPath, 'copy', [])
+  return { destination: destPath, fallbackFiles: [] }
 }
 
 function listKitPolicies() {
@@ -817,12 +907,17 @@ async function applyKit(values) {
     const targetSkillParent = path.join(projectPath, targetAgent.projectPath)
     for (const item of effectiveItems) {
       try {
-        const destination = await syncSkill(item.skillPath, targetSkillParent, applyMode)
+        const { destination, fallbackFiles } = await syncSkill(
+          item.skillPath,
+          targetSkillParent,
+          applyMode
+        )
         loadoutResults.push({
           skillPath: item.skillPath,
           mode: applyMode,
           destination,
           status: 'success',
+          fallbackFiles,
         })
       } catch (error) {
         loadoutResults.push({
@@ -14,6 +14,251 @@ export interface Skill {
     sourcePackageName?: string;
     sourceKitId?: string;
     sourceKitName?: string;
+    dependencies?: string[];
+    diagnostics?: SkillDiagnostic[];
+    inventory?: SkillInventory;
+}
+
+export interface SkillInventory {
+    fileCount: number;
+    totalSize: number;
+    largestFiles: { path: string; size: number }[];
+    hasScripts: boolean;
+    hasBinaries: boolean;
+    lastModified?: number | null;
+}
+
+export interface SkillDiagnostic {
+    code: string;
+    severity: 'error' | 'warning';
+    message: string;
+    file?: string | null;
+    line?: number | null;
+}
+
+export type SkillCopyState = 'current' | 'outdated' | 'modified' | 'conflict' | 'untracked' | 'linked' | 'error';
+
+export interface SkillCopyStatus {
+    path: string;
+    location: SkillLocation;
+    agentName?: string | null;
+    projectName?: string | null;
+    projectPath?: string | null;
+    enabled: boolean;
+    state: SkillCopyState;
+    sourcePackageId?: string | null;
+    sourcePackageName?: string | null;
+    sourceKitId?: string | null;
+    sourceKitName?: string | null;
+    error?: string | null;
+}
+
+export interface SkillResyncItemResult {
+    destination: string;
+    previousState: SkillCopyState;
+    action: string;
+    status: 'success' | 'failed';
+    error?: string | null;
+    conflictFiles: string[];
+}
+
+export interface SkillResyncResult {
+    skillPath: string;
+    updatedCount: number;
+    conflictCount: number;
+    results: SkillResyncItemResult[];
+}
+
+export interface SkillUpdateCommit {
+    commit: string;
+    author: string;
+    date: string;
+    summary: string;
+}
+
+export type GitErrorKind =
+    | 'authRequired'
+    | 'refNotFound'
+    | 'repositoryNotFound'
+    | 'networkUnreachable'
+    | 'other';
+
+export interface GitErrorInfo {
+    kind: GitErrorKind;
+    host: string | null;
+    message: string;
+}
+
+export interface SkillUpdateCheck {
+    kind: 'loadout' | 'skill';
+    id: string;
+    name: string;
+    repoUrl: string;
+    branch?: string | null;
+    pinnedRef?: string | null;
+    currentCommit?: string | null;
+    remoteCommit?: string | null;
+    status: 'current' | 'available' | 'failed';
+    message?: string | null;
+    skills: { skillPath: string; name: string; subdir: string; commits: SkillUpdateCommit[] }[];
+}
+
+export interface GitCacheEntry {
+    repoUrl: string;
+    path: string;
+    size: number;
+    lastUsed: number;
+}
+
+export interface GitCacheGcResult {
+    removed: GitCacheEntry[];
+    freedBytes: number;
+    remainingBytes: number;
+}
+
+export type GitCredentialKind = 'token' | 'sshKey';
+
+export interface GitHostCredential {
+    host: string;
+    kind: GitCredentialKind;
+    username?: string | null;
+    sshKeyPath?: string | null;
+    hasSecret: boolean;
+    updatedAt: number;
+}
+
+export interface SkillLinkHealth {
+    path: string;
+    linkTarget: string;
+    issue: 'dangling' | 'foreign';
+    location: SkillLocation;
+    agentName?: string | null;
+    projectName?: string | null;
+    projectPath?: string | null;
+    suggestedSkillPath?: string | null;
+}
+
+export type SkillLinkRepairAction = 'relink' | 'copy' | 'remove';
+
+export interface SkillPathIssue {
+    path: string;
+    error: string;
+}
+
+export interface HubRelocationResult {
+    previousHubPath: string;
+    hubPath: string;
+    moved: boolean;
+    rewrittenPathCount: number;
+    retargetedLinkCount: number;
+    restampedCopyCount: number;
+    issues: SkillPathIssue[];
+}
+
+export interface SkillVersion {
+    id: string;
+    createdAt: number;
+    reason: string;
+    contentHash: string;
+    fileCount: number;
+}
+
+export interface SkillFileDiff {
+    path: string;
+    change: 'added' | 'removed' | 'modified';
+    // Unified diff; null for binary files.
+    patch?: string | null;
+}
+
+export interface SkillVersionDiff {
+    skillPath: string;
+    fromVersion: string;
+    toVersion?: string | null;
+    files: SkillFileDiff[];
+}
+
+export interface SkillDocument {
+    metadata: Record<string, string>;
+    content: string;
+}
+
+export interface SkillFileUpdateInput {
+    relativePath: string;
+    // Null deletes the file.
+    content?: string | null;
+}
+
+export interface SkillUpdateResult {
+    path: string;
+    previousPath: string;
+    renamed: boolean;
+    rewrittenPathCount: number;
+    retargetedLinkCount: number;
+    restampedCopyCount: number;
+    issues: SkillPathIssue[];
+}
+
+export interface SkillProvenanceMigrationResult {
+    migratedCount: number;
+    issues: SkillPathIssue[];
+}
+
+export interface SkillSearchQuery {
+    query: string;
+    location?: SkillLocation;
+    agentName?: string;
+    projectPath?: string;
+    packageId?: string;
+    kitId?: string;
+    limit?: number;
+}
+
+export interface SkillSearchResult {
+    skill: Skill;
+    score: number;
+    matchedFields: string[];
+}
+
+export interface SkillDuplicateMember {
+    skill: Skill;
+    contentHash: string;
+    inHub: boolean;
+}
+
+export interface SkillDuplicateGroup {
+    kind: 'identical' | 'similar';
+    name: string;
+    contentHashes: string[];
+    skills: SkillDuplicateMember[];
+}
+
+export interface SkillConsolidationResult {
+    hubSkillPath: string;
+    linked: { path: string; backupPath?: string | null }[];
+    issues: SkillPathIssue[];
+}
+
+export interface SkillAdoptionCandidate {
+    skill: Skill;
+    hubDestination: string;
+    status: 'new' | 'identical' | 'conflict';
+    contentHash: string;
+}
+
+export interface SkillAdoptionInput {
+    sourcePath: string;
+    // Required for conflicts with an existing hub skill.
+    resolution?: 'keep' | 'replace' | 'rename';
+    renameTo?: string;
+}
+
+export interface SkillAdoptionItemResult {
+    sourcePath: string;
+    hubSkillPath?: string | null;
+    action: 'created' | 'replaced' | 'kept' | 'unchanged' | 'skipped';
+    linked: boolean;
+    backupPath?: string | null;
+    error?: string | null;
 }
 
 // Deprecated: PLATFORM_NAMES and PLATFORM_LOCAL_PATHS are now dynamic in AppConfig